@@ -0,0 +1,120 @@
+//! WebAssembly Component Model bindings for `wit/monitor.wit`.
+//!
+//! This is the typed alternative to the `#[wasm_bindgen]` JSON-string API in `lib.rs`: a
+//! Wasmtime host can call `get-memory-info()` and get a typed `memory-info` record back
+//! directly, with no `serde_json` round-trip on either side. It targets `wasm32-wasip1` (or
+//! `wasm32-unknown-unknown` built with `cargo component`) rather than the browser, so it
+//! lives behind the `component-model` feature and is not compiled into the `wasm_bindgen`
+//! build.
+
+use std::cell::RefCell;
+use sysinfo::{Disks, System};
+
+wit_bindgen::generate!({
+    world: "monitor-world",
+    path: "wit/monitor.wit",
+});
+
+use exports::softwarewrighter::monitor::types::{CpuInfo, DiskInfo, Guest, GuestMonitor, MemoryInfo, SystemInfo};
+
+/// Component Model resource methods only take `&self`, so the native `System` handle lives
+/// behind a `RefCell` to let `refresh` mutate it in place.
+pub struct Monitor {
+    sys: RefCell<System>,
+}
+
+/// The component root exported to the host; `wit_bindgen` wires this up via `export!` below.
+pub struct Component;
+
+impl Guest for Component {
+    type Monitor = Monitor;
+}
+
+impl GuestMonitor for Monitor {
+    fn new() -> Self {
+        let mut sys = System::new_all();
+        sys.refresh_all();
+        Self {
+            sys: RefCell::new(sys),
+        }
+    }
+
+    fn refresh(&self) {
+        self.sys.borrow_mut().refresh_all();
+    }
+
+    fn get_system_info(&self) -> SystemInfo {
+        let sys = self.sys.borrow();
+        SystemInfo {
+            os: System::name().unwrap_or_else(|| "Unknown".to_string()),
+            os_version: System::os_version().unwrap_or_else(|| "Unknown".to_string()),
+            kernel_version: System::kernel_version().unwrap_or_else(|| "Unknown".to_string()),
+            hostname: System::host_name().unwrap_or_else(|| "Unknown".to_string()),
+            cpu_count: sys.cpus().len() as u32,
+            total_memory: sys.total_memory(),
+            used_memory: sys.used_memory(),
+            uptime: System::uptime(),
+        }
+    }
+
+    fn get_memory_info(&self) -> MemoryInfo {
+        let sys = self.sys.borrow();
+        let total = sys.total_memory();
+        let used = sys.used_memory();
+        let available = sys.available_memory();
+        let usage_percent = if total > 0 {
+            (used as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        MemoryInfo {
+            total,
+            used,
+            available,
+            usage_percent,
+            swap_total: sys.total_swap(),
+            swap_used: sys.used_swap(),
+            swap_free: sys.free_swap(),
+        }
+    }
+
+    fn list_disks(&self) -> Vec<DiskInfo> {
+        Disks::new_with_refreshed_list()
+            .iter()
+            .map(|disk| {
+                let total = disk.total_space();
+                let available = disk.available_space();
+                let used = total.saturating_sub(available);
+                let usage_percent = if total > 0 {
+                    (used as f64 / total as f64) * 100.0
+                } else {
+                    0.0
+                };
+
+                DiskInfo {
+                    name: disk.name().to_string_lossy().to_string(),
+                    mount_point: disk.mount_point().to_string_lossy().to_string(),
+                    total_space: total,
+                    available_space: available,
+                    usage_percent,
+                }
+            })
+            .collect()
+    }
+
+    fn get_cpu_info(&self) -> Vec<CpuInfo> {
+        self.sys
+            .borrow()
+            .cpus()
+            .iter()
+            .map(|cpu| CpuInfo {
+                name: cpu.name().to_string(),
+                usage: cpu.cpu_usage(),
+                frequency: cpu.frequency(),
+            })
+            .collect()
+    }
+}
+
+export!(Component);
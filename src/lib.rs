@@ -3,12 +3,30 @@
 //! This library demonstrates the code-first approach described in Anthropic's
 //! "Code execution with MCP" paper, achieving 98.7% reduction in token usage
 //! by having AI agents write code instead of calling tools directly.
+//!
+//! The `#[wasm_bindgen]` API below targets the browser and returns JSON strings for
+//! convenience on that side of the FFI boundary. For a Wasmtime host, the `component-model`
+//! feature builds this crate as a proper WebAssembly component (see `wit/monitor.wit` and
+//! [`component`]) with typed records instead of stringly-typed JSON.
+
+#[cfg(feature = "component-model")]
+mod component;
 
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 #[cfg(not(target_arch = "wasm32"))]
-use sysinfo::{Disks, System};
+use sysinfo::{Components, Disks, Networks, Pid, System};
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::VecDeque;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(not(target_arch = "wasm32"))]
+use sysinfo::MINIMUM_CPU_UPDATE_INTERVAL;
 
 /// System information structure
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -30,6 +48,17 @@ pub struct MemoryInfo {
     pub used: u64,
     pub available: u64,
     pub usage_percent: f64,
+    pub swap_total: u64,
+    pub swap_used: u64,
+    pub swap_free: u64,
+}
+
+/// System load average over 1, 5, and 15 minutes
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
 }
 
 /// Disk mount information
@@ -50,11 +79,110 @@ pub struct CpuInfo {
     pub frequency: u64,
 }
 
+/// A single running process
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub cmd: Vec<String>,
+    pub cpu_usage: f32,
+    pub memory: u64,
+    pub disk_read_bytes: u64,
+    pub disk_written_bytes: u64,
+    pub run_time: u64,
+    pub status: String,
+}
+
+/// Network interface statistics, including per-refresh transfer rates
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NetworkInfo {
+    pub interface_name: String,
+    pub received: u64,
+    pub transmitted: u64,
+    pub total_received: u64,
+    pub total_transmitted: u64,
+    pub rx_rate: f64,
+    pub tx_rate: f64,
+}
+
+/// Previous counters for a single network interface, used to derive transfer rates
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy)]
+struct NetworkSample {
+    total_received: u64,
+    total_transmitted: u64,
+}
+
+/// A single timestamped entry in the history ring buffer.
+///
+/// `cpu_percent` is `None` when the sample was taken less than
+/// [`sysinfo::MINIMUM_CPU_UPDATE_INTERVAL`] after the previous CPU reading, since sysinfo's
+/// `cpu_usage()` is meaningless (and typically 0%) on back-to-back reads that close together.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HistorySample {
+    pub timestamp_ms: u64,
+    pub memory_percent: f64,
+    pub cpu_percent: Option<Vec<f32>>,
+    pub rx_rate: f64,
+    pub tx_rate: f64,
+}
+
+/// Fixed-capacity ring buffer of [`HistorySample`]s; the oldest sample is dropped once it is
+/// full. The CPU sample spacing is tracked on `SystemMonitor::last_cpu_refresh`, not here.
+#[cfg(not(target_arch = "wasm32"))]
+struct History {
+    capacity: usize,
+    samples: VecDeque<HistorySample>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl History {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, sample: HistorySample) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+}
+
+/// A hardware sensor reading (CPU package, NVMe, chipset, etc.)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ComponentInfo {
+    pub label: String,
+    pub temperature: Option<f32>,
+    pub max: Option<f32>,
+    pub critical: Option<f32>,
+}
+
 /// Main monitor interface exposed to WASM
 #[wasm_bindgen]
 pub struct SystemMonitor {
     #[cfg(not(target_arch = "wasm32"))]
     sys: System,
+    #[cfg(not(target_arch = "wasm32"))]
+    networks: Networks,
+    #[cfg(not(target_arch = "wasm32"))]
+    prev_network_samples: HashMap<String, NetworkSample>,
+    #[cfg(not(target_arch = "wasm32"))]
+    prev_network_refresh: Option<Instant>,
+    #[cfg(not(target_arch = "wasm32"))]
+    history: Option<History>,
+    /// When CPU usage was last actually refreshed (via [`Self::refresh`] or
+    /// [`Self::get_cpu_info`]), shared so the history sampler can tell whether sysinfo had
+    /// enough time since the *previous* refresh to produce a meaningful `cpu_usage()`.
+    #[cfg(not(target_arch = "wasm32"))]
+    last_cpu_refresh: Instant,
 }
 
 #[wasm_bindgen]
@@ -66,7 +194,14 @@ impl SystemMonitor {
         {
             let mut sys = System::new_all();
             sys.refresh_all();
-            Self { sys }
+            Self {
+                sys,
+                networks: Networks::new_with_refreshed_list(),
+                prev_network_samples: HashMap::new(),
+                prev_network_refresh: None,
+                history: None,
+                last_cpu_refresh: Instant::now(),
+            }
         }
         #[cfg(target_arch = "wasm32")]
         {
@@ -74,11 +209,99 @@ impl SystemMonitor {
         }
     }
 
-    /// Refresh system information
+    /// Create a new system monitor instance that also records a bounded history of memory,
+    /// CPU, and network samples each time [`Self::refresh`] is called
+    pub fn new_with_history(capacity: usize) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut monitor = Self::new();
+            monitor.history = Some(History::new(capacity));
+            monitor
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = capacity;
+            Self::new()
+        }
+    }
+
+    /// Refresh system information, recording a history sample if history is enabled
     pub fn refresh(&mut self) {
         #[cfg(not(target_arch = "wasm32"))]
         {
+            let now = Instant::now();
+            let cpu_valid = now.duration_since(self.last_cpu_refresh) >= MINIMUM_CPU_UPDATE_INTERVAL;
             self.sys.refresh_all();
+            self.last_cpu_refresh = now;
+            self.record_history_sample(cpu_valid);
+        }
+    }
+
+    /// Get the buffered history for `metric` (`"memory"`, `"cpu"`, or `"network"`) as JSON
+    pub fn get_history(&self, metric: &str) -> String {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(history) = self.history.as_ref() else {
+                return "[]".to_string();
+            };
+
+            #[derive(Serialize)]
+            struct MemoryPoint {
+                timestamp_ms: u64,
+                value: f64,
+            }
+            #[derive(Serialize)]
+            struct CpuPoint {
+                timestamp_ms: u64,
+                values: Option<Vec<f32>>,
+            }
+            #[derive(Serialize)]
+            struct NetworkPoint {
+                timestamp_ms: u64,
+                rx_rate: f64,
+                tx_rate: f64,
+            }
+
+            match metric {
+                "memory" => serde_json::to_string(
+                    &history
+                        .samples
+                        .iter()
+                        .map(|s| MemoryPoint {
+                            timestamp_ms: s.timestamp_ms,
+                            value: s.memory_percent,
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                "cpu" => serde_json::to_string(
+                    &history
+                        .samples
+                        .iter()
+                        .map(|s| CpuPoint {
+                            timestamp_ms: s.timestamp_ms,
+                            values: s.cpu_percent.clone(),
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                "network" => serde_json::to_string(
+                    &history
+                        .samples
+                        .iter()
+                        .map(|s| NetworkPoint {
+                            timestamp_ms: s.timestamp_ms,
+                            rx_rate: s.rx_rate,
+                            tx_rate: s.tx_rate,
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                _ => return "[]".to_string(),
+            }
+            .unwrap_or_else(|_| "[]".to_string())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = metric;
+            "[]".to_string()
         }
     }
 
@@ -103,7 +326,21 @@ impl SystemMonitor {
         }
         #[cfg(target_arch = "wasm32")]
         {
-            r#"{"os":"WASM","os_version":"N/A","kernel_version":"N/A","hostname":"browser","cpu_count":0,"total_memory":0,"used_memory":0,"uptime":0}"#.to_string()
+            let user_agent = browser::user_agent();
+            let (used, total, _limit) = browser::heap_memory();
+
+            let info = SystemInfo {
+                os: browser::os_from_user_agent(&user_agent),
+                os_version: "Unknown".to_string(),
+                kernel_version: "Unknown".to_string(),
+                hostname: browser::platform(),
+                cpu_count: browser::hardware_concurrency(),
+                total_memory: total,
+                used_memory: used,
+                uptime: 0,
+            };
+
+            serde_json::to_string(&info).unwrap_or_else(|_| "{}".to_string())
         }
     }
 
@@ -127,13 +364,58 @@ impl SystemMonitor {
                 used,
                 available,
                 usage_percent,
+                swap_total: self.sys.total_swap(),
+                swap_used: self.sys.used_swap(),
+                swap_free: self.sys.free_swap(),
             };
 
             serde_json::to_string(&info).unwrap_or_else(|_| "{}".to_string())
         }
         #[cfg(target_arch = "wasm32")]
         {
-            r#"{"total":0,"used":0,"available":0,"usage_percent":0.0}"#.to_string()
+            // `jsHeapSizeLimit` is the ceiling the engine will grow the heap to, so it's used
+            // as the denominator for both `total` and `available` to keep them consistent
+            // (`total == used + available`), the same invariant the native branch holds.
+            let (used, _allocated, limit) = browser::heap_memory();
+            let total = limit;
+            let available = limit.saturating_sub(used);
+            let usage_percent = if limit > 0 {
+                (used as f64 / limit as f64) * 100.0
+            } else {
+                0.0
+            };
+
+            // The browser exposes no swap concept, so these stay zero.
+            let info = MemoryInfo {
+                total,
+                used,
+                available,
+                usage_percent,
+                swap_total: 0,
+                swap_used: 0,
+                swap_free: 0,
+            };
+
+            serde_json::to_string(&info).unwrap_or_else(|_| "{}".to_string())
+        }
+    }
+
+    /// Get the 1/5/15-minute load average as JSON string
+    pub fn get_load_average(&self) -> String {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let load = System::load_average();
+            let info = LoadAverage {
+                one: load.one,
+                five: load.five,
+                fifteen: load.fifteen,
+            };
+
+            serde_json::to_string(&info).unwrap_or_else(|_| "{}".to_string())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            r#"{"one":0.0,"five":0.0,"fifteen":0.0}"#.to_string()
         }
     }
 
@@ -172,11 +454,35 @@ impl SystemMonitor {
         }
     }
 
+    /// List all hardware sensor components (temperatures) as JSON string
+    pub fn list_components(&self) -> String {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let components = Components::new_with_refreshed_list();
+            let component_infos: Vec<ComponentInfo> = components
+                .iter()
+                .map(|component| ComponentInfo {
+                    label: component.label().to_string(),
+                    temperature: component.temperature(),
+                    max: component.max(),
+                    critical: component.critical(),
+                })
+                .collect();
+
+            serde_json::to_string(&component_infos).unwrap_or_else(|_| "[]".to_string())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            "[]".to_string()
+        }
+    }
+
     /// Get CPU information as JSON string
     pub fn get_cpu_info(&mut self) -> String {
         #[cfg(not(target_arch = "wasm32"))]
         {
             self.sys.refresh_cpu_all();
+            self.last_cpu_refresh = Instant::now();
 
             let cpu_infos: Vec<CpuInfo> = self
                 .sys
@@ -196,6 +502,286 @@ impl SystemMonitor {
             "[]".to_string()
         }
     }
+
+    /// Get network interface statistics, including per-refresh transfer rates, as JSON string
+    pub fn get_network_info(&mut self) -> String {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            serde_json::to_string(&self.network_infos()).unwrap_or_else(|_| "[]".to_string())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            "[]".to_string()
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SystemMonitor {
+    /// Refresh networks and compute current per-interface stats, including transfer rates
+    fn network_infos(&mut self) -> Vec<NetworkInfo> {
+        self.networks.refresh(true);
+
+        let now = Instant::now();
+        let elapsed_secs = self
+            .prev_network_refresh
+            .map(|prev| now.duration_since(prev).as_secs_f64())
+            .unwrap_or(0.0);
+
+        let mut current_samples = HashMap::with_capacity(self.networks.len());
+        let network_infos: Vec<NetworkInfo> = self
+            .networks
+            .iter()
+            .map(|(interface_name, data)| {
+                let total_received = data.total_received();
+                let total_transmitted = data.total_transmitted();
+
+                let rate = |total: u64, previous: u64| -> f64 {
+                    if elapsed_secs <= 0.0 || total < previous {
+                        0.0
+                    } else {
+                        (total - previous) as f64 / elapsed_secs
+                    }
+                };
+
+                let (rx_rate, tx_rate) = match self.prev_network_samples.get(interface_name) {
+                    Some(prev) => (
+                        rate(total_received, prev.total_received),
+                        rate(total_transmitted, prev.total_transmitted),
+                    ),
+                    None => (0.0, 0.0),
+                };
+
+                current_samples.insert(
+                    interface_name.clone(),
+                    NetworkSample {
+                        total_received,
+                        total_transmitted,
+                    },
+                );
+
+                NetworkInfo {
+                    interface_name: interface_name.clone(),
+                    received: data.received(),
+                    transmitted: data.transmitted(),
+                    total_received,
+                    total_transmitted,
+                    rx_rate,
+                    tx_rate,
+                }
+            })
+            .collect();
+
+        self.prev_network_samples = current_samples;
+        self.prev_network_refresh = Some(now);
+
+        network_infos
+    }
+
+    /// Append a sample to the history buffer, if history is enabled.
+    ///
+    /// `cpu_valid` reflects whether at least [`MINIMUM_CPU_UPDATE_INTERVAL`] has elapsed
+    /// since the *previous* CPU refresh (tracked on `self.last_cpu_refresh` and shared with
+    /// [`Self::get_cpu_info`]) — sysinfo's `cpu_usage()` is only meaningful once that much
+    /// time separates two refreshes, so a too-soon sample is recorded as `None` rather than
+    /// a bogus 0%.
+    fn record_history_sample(&mut self, cpu_valid: bool) {
+        if self.history.is_none() {
+            return;
+        }
+
+        let total_memory = self.sys.total_memory();
+        let used_memory = self.sys.used_memory();
+        let memory_percent = if total_memory > 0 {
+            (used_memory as f64 / total_memory as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        let cpu_percent = if cpu_valid {
+            Some(self.sys.cpus().iter().map(|c| c.cpu_usage()).collect())
+        } else {
+            None
+        };
+
+        let network_infos = self.network_infos();
+        let rx_rate = network_infos.iter().map(|n| n.rx_rate).sum();
+        let tx_rate = network_infos.iter().map(|n| n.tx_rate).sum();
+
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+
+        self.history.as_mut().unwrap().push(HistorySample {
+            timestamp_ms,
+            memory_percent,
+            cpu_percent,
+            rx_rate,
+            tx_rate,
+        });
+    }
+}
+
+#[wasm_bindgen]
+impl SystemMonitor {
+    /// List all running processes as JSON string
+    pub fn list_processes(&mut self) -> String {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+            let processes: Vec<ProcessInfo> =
+                self.sys.processes().values().map(process_info).collect();
+
+            serde_json::to_string(&processes).unwrap_or_else(|_| "[]".to_string())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            "[]".to_string()
+        }
+    }
+
+    /// Get a single process by pid as JSON string, or `"null"` if it doesn't exist
+    pub fn get_process(&mut self, pid: u32) -> String {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+            let info = self.sys.process(Pid::from_u32(pid)).map(process_info);
+            serde_json::to_string(&info).unwrap_or_else(|_| "null".to_string())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = pid;
+            "null".to_string()
+        }
+    }
+
+    /// Top `limit` processes sorted by `"cpu"` or `"memory"`, as JSON string
+    pub fn top_processes(&mut self, by: &str, limit: usize) -> String {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+            let mut processes: Vec<ProcessInfo> =
+                self.sys.processes().values().map(process_info).collect();
+
+            match by {
+                "memory" => processes.sort_by_key(|p| std::cmp::Reverse(p.memory)),
+                _ => processes.sort_by(|a, b| {
+                    b.cpu_usage
+                        .partial_cmp(&a.cpu_usage)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                }),
+            }
+            processes.truncate(limit);
+
+            serde_json::to_string(&processes).unwrap_or_else(|_| "[]".to_string())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = (by, limit);
+            "[]".to_string()
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn process_info(process: &sysinfo::Process) -> ProcessInfo {
+    let disk_usage = process.disk_usage();
+
+    ProcessInfo {
+        pid: process.pid().as_u32(),
+        parent_pid: process.parent().map(|p| p.as_u32()),
+        name: process.name().to_string_lossy().to_string(),
+        cmd: process
+            .cmd()
+            .iter()
+            .map(|s| s.to_string_lossy().to_string())
+            .collect(),
+        cpu_usage: process.cpu_usage(),
+        memory: process.memory(),
+        disk_read_bytes: disk_usage.total_read_bytes,
+        disk_written_bytes: disk_usage.total_written_bytes,
+        run_time: process.run_time(),
+        status: process.status().to_string(),
+    }
+}
+
+/// Thin wrappers around browser Web APIs backing the wasm32 build, requiring the `Window`,
+/// `Navigator`, and `Performance` `web-sys` features plus `js-sys`.
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use wasm_bindgen::JsValue;
+
+    /// `navigator.userAgent`, or an empty string if unavailable
+    pub fn user_agent() -> String {
+        web_sys::window()
+            .and_then(|w| w.navigator().user_agent().ok())
+            .unwrap_or_default()
+    }
+
+    /// `navigator.platform`, or an empty string if unavailable
+    pub fn platform() -> String {
+        web_sys::window()
+            .and_then(|w| w.navigator().platform().ok())
+            .unwrap_or_default()
+    }
+
+    /// `navigator.hardwareConcurrency`, or 0 if unavailable
+    pub fn hardware_concurrency() -> usize {
+        web_sys::window()
+            .map(|w| w.navigator().hardware_concurrency() as usize)
+            .unwrap_or(0)
+    }
+
+    /// Best-effort OS name parsed out of a `navigator.userAgent` string
+    pub fn os_from_user_agent(user_agent: &str) -> String {
+        let ua = user_agent.to_lowercase();
+        if ua.contains("windows") {
+            "Windows".to_string()
+        } else if ua.contains("android") {
+            "Android".to_string()
+        } else if ua.contains("iphone") || ua.contains("ipad") {
+            "iOS".to_string()
+        } else if ua.contains("mac os") || ua.contains("macintosh") {
+            "macOS".to_string()
+        } else if ua.contains("linux") {
+            "Linux".to_string()
+        } else {
+            "Unknown".to_string()
+        }
+    }
+
+    /// `(used, total, limit)` JS heap bytes from `performance.memory`.
+    ///
+    /// `performance.memory` is a non-standard Chromium extension, so this feature-detects
+    /// the property via `Reflect` and falls back to zeros on engines without it (Firefox,
+    /// Safari).
+    pub fn heap_memory() -> (u64, u64, u64) {
+        let memory = web_sys::window()
+            .and_then(|w| w.performance())
+            .and_then(|perf| js_sys::Reflect::get(&perf, &JsValue::from_str("memory")).ok())
+            .filter(|memory| !memory.is_undefined() && !memory.is_null());
+
+        let Some(memory) = memory else {
+            return (0, 0, 0);
+        };
+
+        let field = |name: &str| -> u64 {
+            js_sys::Reflect::get(&memory, &JsValue::from_str(name))
+                .ok()
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.0) as u64
+        };
+
+        (
+            field("usedJSHeapSize"),
+            field("totalJSHeapSize"),
+            field("jsHeapSizeLimit"),
+        )
+    }
 }
 
 impl Default for SystemMonitor {
@@ -261,4 +847,107 @@ mod tests {
         let parsed: Vec<CpuInfo> = serde_json::from_str(&info).expect("Should be valid JSON");
         assert!(!parsed.is_empty());
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_list_processes() {
+        let mut monitor = SystemMonitor::new();
+        let processes = monitor.list_processes();
+
+        let parsed: Vec<ProcessInfo> =
+            serde_json::from_str(&processes).expect("Should be valid JSON");
+        assert!(!parsed.is_empty());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_list_components() {
+        let monitor = SystemMonitor::new();
+        let components = monitor.list_components();
+
+        let parsed: Vec<ComponentInfo> =
+            serde_json::from_str(&components).expect("Should be valid JSON");
+        for component in parsed {
+            if let Some(temperature) = component.temperature {
+                assert!((-100.0..1000.0).contains(&temperature));
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_get_network_info_first_sample_has_zero_rate() {
+        let mut monitor = SystemMonitor::new();
+        let info = monitor.get_network_info();
+
+        let parsed: Vec<NetworkInfo> = serde_json::from_str(&info).expect("Should be valid JSON");
+        for iface in parsed {
+            assert_eq!(iface.rx_rate, 0.0);
+            assert_eq!(iface.tx_rate, 0.0);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_get_load_average() {
+        let monitor = SystemMonitor::new();
+        let load = monitor.get_load_average();
+
+        let parsed: LoadAverage = serde_json::from_str(&load).expect("Should be valid JSON");
+        assert!(parsed.one >= 0.0);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_history_disabled_by_default() {
+        let monitor = SystemMonitor::new();
+        assert_eq!(monitor.get_history("memory"), "[]");
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_history_records_samples_up_to_capacity() {
+        let mut monitor = SystemMonitor::new_with_history(2);
+        monitor.refresh();
+        monitor.refresh();
+        monitor.refresh();
+
+        let history: Vec<serde_json::Value> =
+            serde_json::from_str(&monitor.get_history("memory")).expect("Should be valid JSON");
+        assert_eq!(history.len(), 2);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_history_with_zero_capacity_records_nothing() {
+        let mut monitor = SystemMonitor::new_with_history(0);
+        monitor.refresh();
+        monitor.refresh();
+
+        let history: Vec<serde_json::Value> =
+            serde_json::from_str(&monitor.get_history("memory")).expect("Should be valid JSON");
+        assert!(history.is_empty());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_history_flags_cpu_sample_taken_too_soon() {
+        let mut monitor = SystemMonitor::new_with_history(4);
+        monitor.refresh();
+
+        let history: Vec<serde_json::Value> =
+            serde_json::from_str(&monitor.get_history("cpu")).expect("Should be valid JSON");
+        assert_eq!(history.len(), 1);
+        assert!(history[0]["values"].is_null());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_top_processes_respects_limit() {
+        let mut monitor = SystemMonitor::new();
+        let top = monitor.top_processes("memory", 3);
+
+        let parsed: Vec<ProcessInfo> = serde_json::from_str(&top).expect("Should be valid JSON");
+        assert!(parsed.len() <= 3);
+    }
 }